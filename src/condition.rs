@@ -0,0 +1,147 @@
+//! Resource-gated conditions for key sends.
+//!
+//! Lets a key only fire when the target process's current CPU/memory usage
+//! satisfies a predicate, e.g. `{"when": {"cpu_below": 5.0}}` to only press
+//! a key while a game is idle, or `{"when": {"mem_above": "512MB"}}` to
+//! throttle automation to when it's actually doing work.
+
+use crate::error::{PksError, Result};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use sysinfo::{Pid, Process, ProcessesToUpdate, System};
+use std::time::Instant;
+
+/// A predicate evaluated against the target process's current resource usage.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Condition {
+    CpuBelow(f32),
+    CpuAbove(f32),
+    MemBelow(u64),
+    MemAbove(u64),
+}
+
+impl Condition {
+    /// Evaluates the predicate against `process`'s last-refreshed stats.
+    pub fn check(&self, process: &Process) -> bool {
+        match self {
+            Condition::CpuBelow(threshold) => process.cpu_usage() < *threshold,
+            Condition::CpuAbove(threshold) => process.cpu_usage() > *threshold,
+            Condition::MemBelow(threshold) => process.memory() < *threshold,
+            Condition::MemAbove(threshold) => process.memory() > *threshold,
+        }
+    }
+}
+
+#[derive(Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+enum ConditionWire {
+    CpuBelow(f32),
+    CpuAbove(f32),
+    MemBelow(String),
+    MemAbove(String),
+}
+
+impl<'de> Deserialize<'de> for Condition {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Ok(match ConditionWire::deserialize(deserializer)? {
+            ConditionWire::CpuBelow(threshold) => Condition::CpuBelow(threshold),
+            ConditionWire::CpuAbove(threshold) => Condition::CpuAbove(threshold),
+            ConditionWire::MemBelow(raw) => {
+                Condition::MemBelow(parse_byte_size(&raw).map_err(serde::de::Error::custom)?)
+            }
+            ConditionWire::MemAbove(raw) => {
+                Condition::MemAbove(parse_byte_size(&raw).map_err(serde::de::Error::custom)?)
+            }
+        })
+    }
+}
+
+impl Serialize for Condition {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let wire = match self {
+            Condition::CpuBelow(threshold) => ConditionWire::CpuBelow(*threshold),
+            Condition::CpuAbove(threshold) => ConditionWire::CpuAbove(*threshold),
+            Condition::MemBelow(bytes) => ConditionWire::MemBelow(format!("{}B", bytes)),
+            Condition::MemAbove(bytes) => ConditionWire::MemAbove(format!("{}B", bytes)),
+        };
+        wire.serialize(serializer)
+    }
+}
+
+/// Parses human-friendly byte size strings like `"512MB"`, `"2GB"`, `"100"`.
+///
+/// A bare number with no unit suffix is treated as bytes. Units are binary
+/// (1 KB = 1024 bytes). Parsing is case-insensitive.
+pub fn parse_byte_size(value: &str) -> Result<u64> {
+    let trimmed = value.trim();
+    if trimmed.is_empty() {
+        return Err(PksError::invalid_byte_size(value, "byte size string is empty"));
+    }
+
+    let lower = trimmed.to_lowercase();
+    let (number_part, bytes_per_unit) = if let Some(stripped) = lower.strip_suffix("gb") {
+        (stripped, 1024 * 1024 * 1024)
+    } else if let Some(stripped) = lower.strip_suffix("mb") {
+        (stripped, 1024 * 1024)
+    } else if let Some(stripped) = lower.strip_suffix("kb") {
+        (stripped, 1024)
+    } else if let Some(stripped) = lower.strip_suffix('b') {
+        (stripped, 1)
+    } else {
+        (lower.as_str(), 1)
+    };
+
+    let number: u64 = number_part
+        .parse()
+        .map_err(|_| PksError::invalid_byte_size(value, "expected a non-negative integer"))?;
+
+    Ok(number * bytes_per_unit)
+}
+
+/// Refreshes a tracked process's CPU/memory stats and evaluates `Condition`s
+/// against them.
+///
+/// CPU usage needs two samples spaced apart to be meaningful, so `check`
+/// fails closed (returns `false`) on a CPU condition until a second refresh
+/// has happened.
+pub struct ResourceGate {
+    system: System,
+    last_refresh: Option<Instant>,
+}
+
+impl Default for ResourceGate {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ResourceGate {
+    pub fn new() -> Self {
+        Self {
+            system: System::new(),
+            last_refresh: None,
+        }
+    }
+
+    /// Refreshes `pid`'s stats and evaluates `condition` against them.
+    pub fn check(&mut self, pid: u64, condition: &Condition) -> bool {
+        self.system.refresh_processes(ProcessesToUpdate::All, true);
+        let had_previous_sample = self.last_refresh.is_some();
+        self.last_refresh = Some(Instant::now());
+
+        let needs_cpu_sample = matches!(condition, Condition::CpuBelow(_) | Condition::CpuAbove(_));
+        if needs_cpu_sample && !had_previous_sample {
+            return false;
+        }
+
+        match self.system.process(Pid::from_u32(pid as u32)) {
+            Some(process) => condition.check(process),
+            None => false,
+        }
+    }
+}
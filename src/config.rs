@@ -0,0 +1,260 @@
+//! Configuration loading, validation, and duration parsing.
+//!
+//! Configs describe which process to target, which keys to send (either as a
+//! one-shot sequence or as independently-timed keys), and how the automation
+//! should behave (retries, pause hotkey, focus handling).
+
+use crate::condition::Condition;
+use crate::error::{PksError, Result};
+use crate::process_matcher::{CmdlineContains, NameContains, NameExact, NameRegex, ProcessMatcher};
+use serde::{Deserialize, Serialize};
+use std::time::Duration;
+
+/// A single step in a key sequence, fired after the previous step's interval elapses.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct KeyAction {
+    pub key: String,
+    #[serde(
+        deserialize_with = "deserialize_duration",
+        serialize_with = "serialize_duration"
+    )]
+    pub interval_after: Duration,
+
+    /// Only fire this step when the target process's resource usage
+    /// satisfies this condition. Absent means always fire.
+    #[serde(default)]
+    pub when: Option<Condition>,
+}
+
+/// A key that fires repeatedly on its own interval, independent of any sequence.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct IndependentKey {
+    pub key: String,
+    #[serde(
+        deserialize_with = "deserialize_duration",
+        serialize_with = "serialize_duration"
+    )]
+    pub interval: Duration,
+
+    /// Only fire this key when the target process's resource usage
+    /// satisfies this condition. Absent means always fire.
+    #[serde(default)]
+    pub when: Option<Condition>,
+}
+
+fn default_max_retries() -> u32 {
+    10
+}
+
+fn default_pause_hotkey() -> String {
+    "ctrl+alt+r".to_string()
+}
+
+fn default_true() -> bool {
+    true
+}
+
+/// How to pick the target process out of the running process list.
+///
+/// Deserializes from the `match` config field, e.g. `{"match": {"regex": "^Revolution Idle"}}`.
+/// When `Config::process_match` is absent, `process_name` is matched with
+/// case-insensitive substring containment, preserving the crate's original
+/// behavior.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum MatchSpec {
+    Contains(String),
+    Exact(String),
+    Regex(String),
+    CmdlineContains(String),
+}
+
+impl MatchSpec {
+    /// Builds the concrete `ProcessMatcher` this spec describes.
+    pub fn to_matcher(&self) -> Result<Box<dyn ProcessMatcher>> {
+        Ok(match self {
+            MatchSpec::Contains(needle) => Box::new(NameContains {
+                needle: needle.clone(),
+            }),
+            MatchSpec::Exact(name) => Box::new(NameExact { name: name.clone() }),
+            MatchSpec::Regex(pattern) => Box::new(NameRegex {
+                pattern: regex::Regex::new(pattern).map_err(|e| {
+                    PksError::config_validation(format!("invalid match regex '{}': {}", pattern, e))
+                })?,
+            }),
+            MatchSpec::CmdlineContains(needle) => Box::new(CmdlineContains {
+                needle: needle.clone(),
+            }),
+        })
+    }
+}
+
+/// Top-level configuration for a process-key-sender run.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct Config {
+    pub process_name: String,
+
+    #[serde(default)]
+    pub key_sequence: Vec<KeyAction>,
+
+    #[serde(default)]
+    pub independent_keys: Vec<IndependentKey>,
+
+    #[serde(default = "default_max_retries")]
+    pub max_retries: u32,
+
+    #[serde(default = "default_pause_hotkey")]
+    pub pause_hotkey: String,
+
+    #[serde(default)]
+    pub verbose: bool,
+
+    #[serde(default = "default_true")]
+    pub loop_sequence: bool,
+
+    #[serde(default)]
+    pub repeat_count: u32,
+
+    #[serde(default = "default_true")]
+    pub restore_focus: bool,
+
+    /// Optional override for how the target process is located. Falls back
+    /// to a case-insensitive substring match on `process_name` when absent.
+    #[serde(rename = "match", default)]
+    pub process_match: Option<MatchSpec>,
+
+    /// When the target process exits and a new instance later appears,
+    /// automatically re-acquire it and resume key sending instead of
+    /// staying suspended until the user restarts the automation.
+    #[serde(default = "default_true")]
+    pub auto_resume: bool,
+}
+
+/// The on-disk encoding of a config file, detected from its extension.
+enum ConfigFormat {
+    Json,
+    Toml,
+}
+
+impl ConfigFormat {
+    fn from_path(path: &str) -> Self {
+        if path.to_lowercase().ends_with(".toml") {
+            ConfigFormat::Toml
+        } else {
+            ConfigFormat::Json
+        }
+    }
+
+    fn as_str(&self) -> &'static str {
+        match self {
+            ConfigFormat::Json => "json",
+            ConfigFormat::Toml => "toml",
+        }
+    }
+}
+
+impl Config {
+    /// Returns the matcher this config selects: `process_match` if set,
+    /// otherwise a `NameContains` match on `process_name`.
+    pub fn matcher(&self) -> Result<Box<dyn ProcessMatcher>> {
+        match &self.process_match {
+            Some(spec) => spec.to_matcher(),
+            None => Ok(Box::new(NameContains {
+                needle: self.process_name.clone(),
+            })),
+        }
+    }
+
+    /// Loads and deserializes a config file, detecting JSON vs TOML from
+    /// the file extension (`.toml` vs anything else, which is treated as JSON).
+    pub fn from_file(path: &str) -> Result<Self> {
+        let format = ConfigFormat::from_path(path);
+        let contents = std::fs::read_to_string(path)
+            .map_err(|e| PksError::config_load(path, format.as_str(), e.to_string()))?;
+
+        match format {
+            ConfigFormat::Json => serde_json::from_str(&contents)
+                .map_err(|e| PksError::config_load(path, format.as_str(), e.to_string())),
+            ConfigFormat::Toml => toml::from_str(&contents)
+                .map_err(|e| PksError::config_load(path, format.as_str(), e.to_string())),
+        }
+    }
+
+    /// Serializes this config and writes it to `path`, in JSON or TOML
+    /// depending on the file extension (see `from_file`).
+    pub fn save_to_file(&self, path: &str) -> Result<()> {
+        let format = ConfigFormat::from_path(path);
+        let contents = match format {
+            ConfigFormat::Json => serde_json::to_string_pretty(self)
+                .map_err(|e| PksError::config_save(path, format.as_str(), e.to_string()))?,
+            ConfigFormat::Toml => toml::to_string_pretty(self)
+                .map_err(|e| PksError::config_save(path, format.as_str(), e.to_string()))?,
+        };
+        std::fs::write(path, contents)
+            .map_err(|e| PksError::config_save(path, format.as_str(), e.to_string()))
+    }
+
+    /// Validates that the config describes a runnable automation.
+    pub fn validate(&self) -> Result<()> {
+        if self.process_name.trim().is_empty() {
+            return Err(PksError::config_validation("process_name cannot be empty"));
+        }
+
+        if self.key_sequence.is_empty() && self.independent_keys.is_empty() {
+            return Err(PksError::config_validation(
+                "at least one key_sequence or independent_keys entry is required",
+            ));
+        }
+
+        if self.max_retries == 0 {
+            return Err(PksError::config_validation(
+                "max_retries must be greater than zero",
+            ));
+        }
+
+        Ok(())
+    }
+}
+
+/// Parses human-friendly duration strings like `"1000ms"`, `"5s"`, `"2m"`.
+///
+/// A bare number with no unit suffix is treated as milliseconds. Parsing is
+/// case-insensitive and tolerates surrounding whitespace.
+pub fn parse_duration(value: &str) -> Result<Duration> {
+    let trimmed = value.trim();
+    if trimmed.is_empty() {
+        return Err(PksError::invalid_duration(value, "duration string is empty"));
+    }
+
+    let lower = trimmed.to_lowercase();
+    let (number_part, millis_per_unit) = if let Some(stripped) = lower.strip_suffix("ms") {
+        (stripped, 1)
+    } else if let Some(stripped) = lower.strip_suffix('s') {
+        (stripped, 1000)
+    } else if let Some(stripped) = lower.strip_suffix('m') {
+        (stripped, 60_000)
+    } else {
+        (lower.as_str(), 1)
+    };
+
+    let number: u64 = number_part
+        .parse()
+        .map_err(|_| PksError::invalid_duration(value, "expected a non-negative integer"))?;
+
+    Ok(Duration::from_millis(number * millis_per_unit))
+}
+
+fn deserialize_duration<'de, D>(deserializer: D) -> std::result::Result<Duration, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    let raw = String::deserialize(deserializer)?;
+    parse_duration(&raw).map_err(serde::de::Error::custom)
+}
+
+fn serialize_duration<S>(duration: &Duration, serializer: S) -> std::result::Result<S::Ok, S::Error>
+where
+    S: serde::Serializer,
+{
+    serializer.serialize_str(&format!("{}ms", duration.as_millis()))
+}
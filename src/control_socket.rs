@@ -0,0 +1,147 @@
+//! Runtime IPC control socket.
+//!
+//! Lets another process (or a CLI) drive the automation without a physical
+//! keypress: on Unix, a domain socket accepts newline-delimited commands
+//! (`pause`, `resume`, `toggle`, `mode <name>`, `status`) and applies them
+//! to a `HotkeyManager`. `pause`, `resume` and `toggle` go through
+//! `trigger`, the same dispatch the hotkey listener uses, so they broadcast
+//! over the same `watch` channel; `mode <name>` calls `set_mode`, which
+//! switches the active mode without broadcasting anything. On Windows,
+//! `start_control_socket` always returns an error. Mirrors the mode-socket
+//! design in the swhkd daemon.
+
+use crate::global_hotkey::{Action, HotkeyManager};
+use anyhow::Result;
+use std::sync::Arc;
+
+/// A line command accepted by the control socket.
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum ControlCommand {
+    Pause,
+    Resume,
+    Toggle,
+    Mode(String),
+    Status,
+}
+
+impl ControlCommand {
+    fn parse(line: &str) -> std::result::Result<Self, String> {
+        let mut parts = line.trim().splitn(2, ' ');
+        match parts.next().unwrap_or("").to_lowercase().as_str() {
+            "pause" => Ok(ControlCommand::Pause),
+            "resume" => Ok(ControlCommand::Resume),
+            "toggle" => Ok(ControlCommand::Toggle),
+            "status" => Ok(ControlCommand::Status),
+            "mode" => match parts.next().map(str::trim) {
+                Some(name) if !name.is_empty() => Ok(ControlCommand::Mode(name.to_string())),
+                _ => Err("mode command requires a mode name".to_string()),
+            },
+            "" => Err("empty command".to_string()),
+            other => Err(format!("unrecognized command '{}'", other)),
+        }
+    }
+}
+
+/// Applies a parsed command to `manager` and returns the line to write back
+/// to the client.
+fn apply(manager: &HotkeyManager, command: ControlCommand) -> String {
+    match command {
+        ControlCommand::Pause => {
+            manager.trigger(Action::Pause);
+            "ok\n".to_string()
+        }
+        ControlCommand::Resume => {
+            manager.trigger(Action::Resume);
+            "ok\n".to_string()
+        }
+        ControlCommand::Toggle => {
+            manager.trigger(Action::Toggle);
+            "ok\n".to_string()
+        }
+        ControlCommand::Mode(name) => {
+            manager.set_mode(name.clone());
+            format!("ok mode={}\n", name)
+        }
+        ControlCommand::Status => {
+            format!("mode={} paused={}\n", manager.mode(), manager.is_paused())
+        }
+    }
+}
+
+/// Starts listening on a Unix domain socket at `path`, accepting
+/// newline-delimited commands and applying them to `manager`. A stale
+/// socket file left behind by a previous run is removed before binding.
+#[cfg(unix)]
+pub async fn start_control_socket(manager: Arc<HotkeyManager>, path: &str) -> Result<()> {
+    use tokio::io::{AsyncBufReadExt, AsyncWriteExt, BufReader};
+    use tokio::net::{UnixListener, UnixStream};
+
+    let _ = std::fs::remove_file(path);
+    let listener = UnixListener::bind(path)
+        .map_err(|e| anyhow::anyhow!("Failed to bind control socket '{}': {}", path, e))?;
+
+    async fn handle_connection(manager: Arc<HotkeyManager>, stream: UnixStream) {
+        let (reader, mut writer) = stream.into_split();
+        let mut lines = BufReader::new(reader).lines();
+
+        while let Ok(Some(line)) = lines.next_line().await {
+            let response = match ControlCommand::parse(&line) {
+                Ok(command) => apply(&manager, command),
+                Err(reason) => format!("error: {}\n", reason),
+            };
+            if writer.write_all(response.as_bytes()).await.is_err() {
+                break;
+            }
+        }
+    }
+
+    tokio::spawn(async move {
+        loop {
+            match listener.accept().await {
+                Ok((stream, _)) => {
+                    tokio::spawn(handle_connection(manager.clone(), stream));
+                }
+                Err(e) => eprintln!("Control socket accept error: {}", e),
+            }
+        }
+    });
+
+    Ok(())
+}
+
+/// On Windows there is no control socket: this always returns an error.
+#[cfg(windows)]
+pub async fn start_control_socket(_manager: Arc<HotkeyManager>, _path: &str) -> Result<()> {
+    Err(anyhow::anyhow!(
+        "control socket is not yet implemented on Windows"
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_simple_commands() {
+        assert_eq!(ControlCommand::parse("pause"), Ok(ControlCommand::Pause));
+        assert_eq!(ControlCommand::parse("Resume"), Ok(ControlCommand::Resume));
+        assert_eq!(ControlCommand::parse("  toggle  "), Ok(ControlCommand::Toggle));
+        assert_eq!(ControlCommand::parse("status"), Ok(ControlCommand::Status));
+    }
+
+    #[test]
+    fn test_parse_mode_command() {
+        assert_eq!(
+            ControlCommand::parse("mode gaming"),
+            Ok(ControlCommand::Mode("gaming".to_string()))
+        );
+        assert!(ControlCommand::parse("mode").is_err());
+        assert!(ControlCommand::parse("mode   ").is_err());
+    }
+
+    #[test]
+    fn test_parse_rejects_unknown_and_empty() {
+        assert!(ControlCommand::parse("quit").is_err());
+        assert!(ControlCommand::parse("").is_err());
+    }
+}
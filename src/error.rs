@@ -26,17 +26,29 @@ pub enum PksError {
     ConfigValidation(String),
 
     /// Error reading or parsing configuration file.
-    #[error("failed to load config from '{path}': {reason}")]
-    ConfigLoad { path: String, reason: String },
+    #[error("failed to load {format} config from '{path}': {reason}")]
+    ConfigLoad {
+        path: String,
+        format: String,
+        reason: String,
+    },
 
     /// Error writing configuration file.
-    #[error("failed to save config to '{path}': {reason}")]
-    ConfigSave { path: String, reason: String },
+    #[error("failed to save {format} config to '{path}': {reason}")]
+    ConfigSave {
+        path: String,
+        format: String,
+        reason: String,
+    },
 
     /// Error parsing duration string.
     #[error("invalid duration '{value}': {reason}")]
     InvalidDuration { value: String, reason: String },
 
+    /// Error parsing byte size string.
+    #[error("invalid byte size '{value}': {reason}")]
+    InvalidByteSize { value: String, reason: String },
+
     /// Platform-specific operation is not supported.
     #[error("operation not supported on this platform: {0}")]
     UnsupportedPlatform(String),
@@ -100,9 +112,27 @@ impl PksError {
     }
 
     /// Create a new ConfigLoad error.
-    pub fn config_load(path: impl Into<String>, reason: impl Into<String>) -> Self {
+    pub fn config_load(
+        path: impl Into<String>,
+        format: impl Into<String>,
+        reason: impl Into<String>,
+    ) -> Self {
         Self::ConfigLoad {
             path: path.into(),
+            format: format.into(),
+            reason: reason.into(),
+        }
+    }
+
+    /// Create a new ConfigSave error.
+    pub fn config_save(
+        path: impl Into<String>,
+        format: impl Into<String>,
+        reason: impl Into<String>,
+    ) -> Self {
+        Self::ConfigSave {
+            path: path.into(),
+            format: format.into(),
             reason: reason.into(),
         }
     }
@@ -115,6 +145,14 @@ impl PksError {
         }
     }
 
+    /// Create a new InvalidByteSize error.
+    pub fn invalid_byte_size(value: impl Into<String>, reason: impl Into<String>) -> Self {
+        Self::InvalidByteSize {
+            value: value.into(),
+            reason: reason.into(),
+        }
+    }
+
     /// Create a new UnsupportedPlatform error.
     pub fn unsupported_platform(message: impl Into<String>) -> Self {
         Self::UnsupportedPlatform(message.into())
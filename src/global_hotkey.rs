@@ -1,90 +1,498 @@
 use anyhow::Result;
 use global_hotkey::{GlobalHotKeyEvent, GlobalHotKeyManager, HotKeyState};
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
 use std::sync::atomic::{AtomicBool, Ordering};
-use std::sync::Arc;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
 use tokio::sync::watch;
 
+const DEFAULT_MODE: &str = "default";
+
+/// How long after its first step a chord-chain binding stays "in progress"
+/// before the sequence resets back to the start.
+const CHAIN_TIMEOUT_MS: u64 = 800;
+
+fn default_cooldown_ms() -> u64 {
+    150
+}
+
+/// Which key-repeat phase a binding fires on.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Trigger {
+    #[default]
+    Press,
+    Release,
+}
+
+impl Trigger {
+    fn matches(self, state: HotKeyState) -> bool {
+        matches!(
+            (self, state),
+            (Trigger::Press, HotKeyState::Pressed) | (Trigger::Release, HotKeyState::Released)
+        )
+    }
+}
+
+/// Something a registered hotkey can trigger.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Action {
+    /// Toggles between paused and resumed, matching the crate's original
+    /// single-hotkey behavior.
+    Toggle,
+    Pause,
+    Resume,
+    Stop,
+    SwitchProfile(String),
+    Burst,
+}
+
+/// One entry in a hotkey config file: which chord (or chord chain, e.g.
+/// `"ctrl+k, p"`), in which mode, triggers which action. `mode` defaults to
+/// the manager's default mode when absent.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct HotkeyBinding {
+    #[serde(default)]
+    pub mode: Option<String>,
+    pub hotkey: String,
+    pub action: Action,
+
+    /// Whether the binding fires on key-down or key-up of its final step.
+    /// Defaults to press.
+    #[serde(default)]
+    pub trigger: Trigger,
+
+    /// Minimum milliseconds between two firings of this chord, so holding
+    /// the key down (which key-repeats `Pressed` events) doesn't flicker
+    /// the bound action.
+    #[serde(default = "default_cooldown_ms")]
+    pub cooldown_ms: u64,
+}
+
+/// A registered chord chain: the sequence of `HotKey`s that must fire in
+/// order, and what firing the final one dispatches.
+#[derive(Debug, Clone)]
+struct ChainBinding {
+    chain: Vec<global_hotkey::hotkey::HotKey>,
+    action: Action,
+    trigger: Trigger,
+    cooldown_ms: u64,
+}
+
+/// Which chain bindings are still a possible match for an in-progress
+/// chord-chain, and how far into the chain the sequence has advanced.
+struct SequenceProgress {
+    mode: String,
+    step: usize,
+    started_at: Instant,
+    /// Indices into `chain_bindings[mode]` that matched every step seen so
+    /// far, so concurrent bindings sharing a prefix (e.g. two chains both
+    /// starting with `ctrl+k`) are tracked deterministically until they
+    /// diverge or one completes.
+    candidates: Vec<usize>,
+}
+
+/// Registers global hotkeys and dispatches the `Action` each one is bound
+/// to, with *modal* keybindings (à la the swhkd daemon): the manager holds
+/// a current mode, and the same physical chord can be bound to a different
+/// action per mode. Bindings may be single chords or chord chains (e.g.
+/// `"ctrl+k, p"`), tracked by a small per-mode sequence state machine.
 pub struct HotkeyManager {
     manager: GlobalHotKeyManager,
+    /// Chord ids already registered with the OS, so the same chord bound
+    /// under multiple modes (or appearing in multiple chains) is only
+    /// registered once.
+    registered: HashSet<u32>,
+    /// mode -> the chain bindings active in it.
+    chain_bindings: HashMap<String, Vec<ChainBinding>>,
+    /// Chord id -> the parsed `HotKey` it came from, so a binding can be
+    /// rendered back to its canonical string (for logging or writing a
+    /// normalized config back to disk) without re-parsing.
+    chords: HashMap<u32, global_hotkey::hotkey::HotKey>,
+    /// (mode, binding index) -> the `Instant` it last completed, for
+    /// debouncing key-repeat.
+    last_fired: Mutex<HashMap<(String, usize), Instant>>,
+    /// The chord chain currently being matched against incoming events, if
+    /// any step beyond the first has already fired.
+    sequence_progress: Mutex<Option<SequenceProgress>>,
+    mode: Arc<Mutex<String>>,
     is_paused: Arc<AtomicBool>,
-    pause_sender: watch::Sender<bool>,
-    pause_receiver: watch::Receiver<bool>,
+    action_sender: watch::Sender<Option<Action>>,
+    action_receiver: watch::Receiver<Option<Action>>,
 }
 
 impl HotkeyManager {
     pub fn new() -> Result<Self> {
         let manager = GlobalHotKeyManager::new()
             .map_err(|e| anyhow::anyhow!("Failed to create GlobalHotKeyManager: {}", e))?;
-        
-        let is_paused = Arc::new(AtomicBool::new(false));
-        let (pause_sender, pause_receiver) = watch::channel(false);
+
+        let (action_sender, action_receiver) = watch::channel(None);
 
         Ok(Self {
             manager,
-            is_paused,
-            pause_sender,
-            pause_receiver,
+            registered: HashSet::new(),
+            chain_bindings: HashMap::new(),
+            chords: HashMap::new(),
+            last_fired: Mutex::new(HashMap::new()),
+            sequence_progress: Mutex::new(None),
+            mode: Arc::new(Mutex::new(DEFAULT_MODE.to_string())),
+            is_paused: Arc::new(AtomicBool::new(false)),
+            action_sender,
+            action_receiver,
         })
     }
 
+    /// Binds `hotkey_str` in `mode` to dispatch `action` on press, with the
+    /// default debounce window. The same chord can be registered under
+    /// multiple modes with different actions; it's only registered with the
+    /// OS the first time it's seen.
+    pub fn register_action(
+        &mut self,
+        mode: &str,
+        hotkey_str: &str,
+        action: Action,
+    ) -> Result<Vec<global_hotkey::hotkey::HotKey>> {
+        self.register_binding(mode, hotkey_str, action, Trigger::Press, default_cooldown_ms())
+    }
+
+    /// Like `register_action`, but with explicit control over which
+    /// key-repeat phase the final step fires on and the binding's debounce
+    /// window. `hotkey_str` may be a single chord (`"ctrl+alt+r"`) or a
+    /// comma-separated chain (`"ctrl+k, p"`); every distinct chord in the
+    /// chain is registered with the OS.
+    pub fn register_binding(
+        &mut self,
+        mode: &str,
+        hotkey_str: &str,
+        action: Action,
+        trigger: Trigger,
+        cooldown_ms: u64,
+    ) -> Result<Vec<global_hotkey::hotkey::HotKey>> {
+        let chain = parse_hotkey_chain(hotkey_str)?;
+
+        for hotkey in &chain {
+            if self.registered.insert(hotkey.id()) {
+                self.manager.register(*hotkey).map_err(|e| {
+                    anyhow::anyhow!("Failed to register hotkey '{}': {}", hotkey_str, e)
+                })?;
+            }
+            self.chords.insert(hotkey.id(), *hotkey);
+        }
+
+        self.chain_bindings
+            .entry(mode.to_string())
+            .or_default()
+            .push(ChainBinding {
+                chain: chain.clone(),
+                action,
+                trigger,
+                cooldown_ms,
+            });
+
+        Ok(chain)
+    }
+
+    /// Registers `hotkey_str` in the default mode to toggle pause/resume,
+    /// preserving the crate's original single-hotkey behavior.
     pub fn register_pause_hotkey(&mut self, hotkey_str: &str) -> Result<()> {
-        let hotkey = parse_hotkey(hotkey_str)?;
-        
-        self.manager.register(hotkey)
-            .map_err(|e| anyhow::anyhow!("Failed to register hotkey '{}': {}", hotkey_str, e))?;
+        let chain = self.register_action(DEFAULT_MODE, hotkey_str, Action::Toggle)?;
+        let label = format_hotkey_chain(&chain).unwrap_or_else(|_| hotkey_str.to_string());
+        println!("🔥 Global pause hotkey '{}' registered successfully", label);
+        Ok(())
+    }
+
+    /// Returns every registered `(mode, canonical hotkey string, action)`
+    /// triple, suitable for logging the active bindings or writing a
+    /// normalized `Vec<HotkeyBinding>` back out to a config file.
+    pub fn describe_bindings(&self) -> Vec<(String, String, Action)> {
+        let mut described: Vec<(String, String, Action)> = self
+            .chain_bindings
+            .iter()
+            .flat_map(|(mode, bindings)| {
+                bindings.iter().map(move |binding| {
+                    let label = format_hotkey_chain(&binding.chain)
+                        .unwrap_or_else(|_| "<unsupported chord>".to_string());
+                    (mode.clone(), label, binding.action.clone())
+                })
+            })
+            .collect();
+
+        described.sort_by(|a, b| a.0.cmp(&b.0).then_with(|| a.1.cmp(&b.1)));
+        described
+    }
+
+    /// Prints every registered binding in `mode: hotkey -> action` form.
+    pub fn print_bindings(&self) {
+        for (mode, hotkey, action) in self.describe_bindings() {
+            println!("🔑 [{}] {} -> {:?}", mode, hotkey, action);
+        }
+    }
+
+    /// Registers every binding described in a config file (JSON array of
+    /// `HotkeyBinding`).
+    pub fn load_bindings_from_file(&mut self, path: &str) -> Result<()> {
+        let contents = std::fs::read_to_string(path)
+            .map_err(|e| anyhow::anyhow!("Failed to read hotkey config '{}': {}", path, e))?;
+        let bindings: Vec<HotkeyBinding> = serde_json::from_str(&contents)
+            .map_err(|e| anyhow::anyhow!("Failed to parse hotkey config '{}': {}", path, e))?;
+
+        for binding in bindings {
+            let mode = binding.mode.as_deref().unwrap_or(DEFAULT_MODE);
+            self.register_binding(
+                mode,
+                &binding.hotkey,
+                binding.action,
+                binding.trigger,
+                binding.cooldown_ms,
+            )?;
+        }
 
-        println!("🔥 Global pause hotkey '{}' registered successfully", hotkey_str);
         Ok(())
     }
 
-    pub fn get_pause_receiver(&self) -> watch::Receiver<bool> {
-        self.pause_receiver.clone()
+    /// Switches the active mode. Hotkeys now resolve against bindings
+    /// registered for `mode`; chords with no binding in the new mode are
+    /// simply ignored until the mode switches again.
+    pub fn set_mode(&self, mode: impl Into<String>) {
+        *self.mode.lock().unwrap() = mode.into();
+    }
+
+    pub fn mode(&self) -> String {
+        self.mode.lock().unwrap().clone()
+    }
+
+    pub fn get_action_receiver(&self) -> watch::Receiver<Option<Action>> {
+        self.action_receiver.clone()
     }
 
     pub fn is_paused(&self) -> bool {
         self.is_paused.load(Ordering::Relaxed)
     }
 
+    /// Dispatches `action` as if a bound hotkey had just fired. Lets other
+    /// control surfaces (e.g. the IPC control socket) drive the same state
+    /// and `watch` broadcast the hotkey listener drives.
+    pub fn trigger(&self, action: Action) {
+        self.dispatch(action);
+    }
+
     pub async fn start_hotkey_listener(self: Arc<Self>) -> Result<()> {
         let receiver = GlobalHotKeyEvent::receiver();
         let manager = self.clone();
 
-        tokio::task::spawn_blocking(move || {
-            loop {
-                if let Ok(event) = receiver.try_recv() {
-                    if event.state == HotKeyState::Pressed {
-                        let current_state = manager.is_paused.load(Ordering::Relaxed);
-                        let new_state = !current_state;
-                        
-                        manager.is_paused.store(new_state, Ordering::Relaxed);
-                        
-                        if let Err(e) = manager.pause_sender.send(new_state) {
-                            eprintln!("Failed to send pause state: {}", e);
-                        }
-
-                        if new_state {
-                            println!("⏸️  Automation PAUSED (press hotkey again to resume)");
-                        } else {
-                            println!("▶️  Automation RESUMED");
-                        }
-                    }
-                }
-                
-                // Small sleep to prevent busy waiting
-                std::thread::sleep(std::time::Duration::from_millis(10));
+        tokio::task::spawn_blocking(move || loop {
+            if let Ok(event) = receiver.try_recv() {
+                manager.handle_event(event);
             }
+
+            // Small sleep to prevent busy waiting
+            std::thread::sleep(std::time::Duration::from_millis(10));
         });
 
         Ok(())
     }
+
+    /// Resolves an incoming OS hotkey event against the active mode's
+    /// chain bindings, advancing the sequence state machine: on a match for
+    /// a non-final step the sequence progresses and waits for the next
+    /// step; on a match for a final step (whose trigger phase and cooldown
+    /// are satisfied) the bound action dispatches and the sequence resets.
+    /// Progress resets on a non-matching key or once `CHAIN_TIMEOUT_MS`
+    /// elapses since the first step fired.
+    fn handle_event(&self, event: GlobalHotKeyEvent) {
+        let mode = self.mode();
+        let Some(chain_bindings) = self.chain_bindings.get(&mode) else {
+            return;
+        };
+
+        let now = Instant::now();
+        let mut progress = self.sequence_progress.lock().unwrap();
+
+        if let Some(p) = progress.as_ref() {
+            if p.mode != mode || now.duration_since(p.started_at) > Duration::from_millis(CHAIN_TIMEOUT_MS) {
+                *progress = None;
+            }
+        }
+
+        let step = progress.as_ref().map_or(0, |p| p.step);
+        let started_at = progress.as_ref().map_or(now, |p| p.started_at);
+        let candidates: Vec<usize> = progress
+            .as_ref()
+            .map(|p| p.candidates.clone())
+            .unwrap_or_else(|| (0..chain_bindings.len()).collect());
+
+        if step > 0 && is_repeat_of_previous_step(chain_bindings, &candidates, step, event.id, event.state) {
+            // The OS key-repeats the chord that just matched while the user
+            // keeps it held down before pressing the next step (e.g. holding
+            // `ctrl+k` before `p` in "ctrl+k, p"), and reports its release
+            // once it's let go. Neither is a different key, so leave the
+            // in-progress sequence alone instead of treating it as a reset.
+            return;
+        }
+
+        let (next_candidates, completed) =
+            advance_sequence(chain_bindings, &candidates, step, event.id, event.state);
+
+        if let Some(idx) = completed {
+            let binding = chain_bindings[idx].clone();
+            *progress = None;
+            drop(progress);
+
+            if self.tick_cooldown((mode, idx), binding.cooldown_ms) {
+                self.dispatch(binding.action);
+            }
+            return;
+        }
+
+        let awaiting_release = step > 0
+            && event.state == HotKeyState::Pressed
+            && candidates.iter().any(|&idx| {
+                let binding = &chain_bindings[idx];
+                binding.trigger == Trigger::Release
+                    && step + 1 == binding.chain.len()
+                    && binding.chain[step].id() == event.id
+            });
+        if next_candidates.is_empty() && awaiting_release {
+            // The final step of a release-triggered chain went down; keep
+            // the sequence where it is so its `Released` event completes it.
+            return;
+        }
+
+        *progress = if next_candidates.is_empty() {
+            None
+        } else {
+            Some(SequenceProgress {
+                mode,
+                step: step + 1,
+                started_at,
+                candidates: next_candidates,
+            })
+        };
+    }
+
+    /// Returns `true` (and records `now` as the new last-fired time) if
+    /// `cooldown_ms` has elapsed since this binding last fired.
+    fn tick_cooldown(&self, key: (String, usize), cooldown_ms: u64) -> bool {
+        let mut last_fired = self.last_fired.lock().unwrap();
+        let now = Instant::now();
+        let ready = match last_fired.get(&key) {
+            Some(&last) => now.duration_since(last) >= Duration::from_millis(cooldown_ms),
+            None => true,
+        };
+        if ready {
+            last_fired.insert(key, now);
+        }
+        ready
+    }
+
+    fn dispatch(&self, action: Action) {
+        match &action {
+            Action::Toggle => {
+                let new_state = !self.is_paused.load(Ordering::Relaxed);
+                self.is_paused.store(new_state, Ordering::Relaxed);
+                if new_state {
+                    println!("⏸️  Automation PAUSED (press hotkey again to resume)");
+                } else {
+                    println!("▶️  Automation RESUMED");
+                }
+            }
+            Action::Pause => {
+                self.is_paused.store(true, Ordering::Relaxed);
+                println!("⏸️  Automation PAUSED");
+            }
+            Action::Resume => {
+                self.is_paused.store(false, Ordering::Relaxed);
+                println!("▶️  Automation RESUMED");
+            }
+            Action::Stop => println!("⏹️  Stop requested"),
+            Action::SwitchProfile(name) => println!("🔀 Switching to profile '{}'", name),
+            Action::Burst => println!("💥 One-shot burst triggered"),
+        }
+
+        if let Err(e) = self.action_sender.send(Some(action)) {
+            eprintln!("Failed to send action: {}", e);
+        }
+    }
 }
 
-fn parse_hotkey(hotkey_str: &str) -> Result<global_hotkey::hotkey::HotKey> {
+/// True if `event` is an OS auto-repeat or the release of the chord that
+/// matched the previous step, rather than a genuinely different key.
+/// `global-hotkey` resends `Pressed` for as long as a chord is held and
+/// sends `Released` when it's let go, so mid-chain (`step > 0`) these events
+/// would otherwise look like a non-matching key and wipe `next` in
+/// `advance_sequence`.
+fn is_repeat_of_previous_step(
+    chain_bindings: &[ChainBinding],
+    candidates: &[usize],
+    step: usize,
+    event_id: u32,
+    _event_state: HotKeyState,
+) -> bool {
+    candidates
+        .iter()
+        .any(|&idx| chain_bindings[idx].chain[step - 1].id() == event_id)
+}
+
+/// Pure step of the sequence state machine: given the chain bindings active
+/// in a mode, the subset of them (`candidates`) still matching every step
+/// seen so far, and an incoming event, returns the candidates that survive
+/// to `step + 1` plus the index of any chain binding that just completed.
+fn advance_sequence(
+    chain_bindings: &[ChainBinding],
+    candidates: &[usize],
+    step: usize,
+    event_id: u32,
+    event_state: HotKeyState,
+) -> (Vec<usize>, Option<usize>) {
+    let mut next = Vec::new();
+    let mut completed = None;
+
+    for &idx in candidates {
+        let binding = &chain_bindings[idx];
+        if step >= binding.chain.len() || binding.chain[step].id() != event_id {
+            continue;
+        }
+
+        if step + 1 == binding.chain.len() {
+            if binding.trigger.matches(event_state) {
+                completed = Some(idx);
+            }
+        } else if event_state == HotKeyState::Pressed {
+            next.push(idx);
+        }
+    }
+
+    (next, completed)
+}
+
+/// Parses a chord chain like `"ctrl+k, p"` into the sequence of `HotKey`s
+/// that must fire, in order, within `CHAIN_TIMEOUT_MS` of each other, to
+/// complete the binding. A chain with no comma is a single-step binding
+/// (the common case), so existing single-combo strings parse unchanged.
+pub fn parse_hotkey_chain(chain_str: &str) -> Result<Vec<global_hotkey::hotkey::HotKey>> {
+    chain_str.split(',').map(|step| parse_hotkey(step.trim())).collect()
+}
+
+/// Renders a chain of `HotKey`s back to the canonical `"ctrl+k, p"` form
+/// `parse_hotkey_chain` accepts.
+pub fn format_hotkey_chain(chain: &[global_hotkey::hotkey::HotKey]) -> Result<String> {
+    let steps = chain
+        .iter()
+        .map(format_hotkey)
+        .collect::<Result<Vec<String>>>()?;
+    Ok(steps.join(", "))
+}
+
+/// Parses a `"ctrl+shift+f5"`-style hotkey string into a `HotKey`, the
+/// inverse of `format_hotkey`.
+pub fn parse_hotkey(hotkey_str: &str) -> Result<global_hotkey::hotkey::HotKey> {
     use global_hotkey::hotkey::{HotKey, Modifiers};
 
     let binding = hotkey_str.to_lowercase();
     let parts: Vec<&str> = binding.split('+').map(|s| s.trim()).collect();
-    
+
     if parts.is_empty() {
         return Err(anyhow::anyhow!("Empty hotkey string"));
     }
@@ -112,6 +520,157 @@ fn parse_hotkey(hotkey_str: &str) -> Result<global_hotkey::hotkey::HotKey> {
     Ok(HotKey::new(Some(modifiers), code))
 }
 
+/// Renders a parsed `HotKey` back to the canonical `"ctrl+shift+f5"` form
+/// `parse_hotkey` accepts, so a parse -> format -> parse round-trip is
+/// idempotent. Modifiers are joined in a stable order ahead of the key name.
+pub fn format_hotkey(hotkey: &global_hotkey::hotkey::HotKey) -> Result<String> {
+    use global_hotkey::hotkey::Modifiers;
+
+    let mut parts = Vec::new();
+
+    if hotkey.mods.contains(Modifiers::CONTROL) {
+        parts.push("ctrl");
+    }
+    if hotkey.mods.contains(Modifiers::ALT) {
+        parts.push("alt");
+    }
+    if hotkey.mods.contains(Modifiers::SHIFT) {
+        parts.push("shift");
+    }
+    if hotkey.mods.contains(Modifiers::SUPER) {
+        parts.push("meta");
+    }
+
+    parts.push(format_key_code(hotkey.key)?);
+
+    Ok(parts.join("+"))
+}
+
+/// Renders a `Code` back to the key name `parse_key_code` accepts. The
+/// mirror image of `parse_key_code`'s match arms.
+fn format_key_code(code: global_hotkey::hotkey::Code) -> Result<&'static str> {
+    use global_hotkey::hotkey::Code;
+
+    Ok(match code {
+        Code::KeyA => "a",
+        Code::KeyB => "b",
+        Code::KeyC => "c",
+        Code::KeyD => "d",
+        Code::KeyE => "e",
+        Code::KeyF => "f",
+        Code::KeyG => "g",
+        Code::KeyH => "h",
+        Code::KeyI => "i",
+        Code::KeyJ => "j",
+        Code::KeyK => "k",
+        Code::KeyL => "l",
+        Code::KeyM => "m",
+        Code::KeyN => "n",
+        Code::KeyO => "o",
+        Code::KeyP => "p",
+        Code::KeyQ => "q",
+        Code::KeyR => "r",
+        Code::KeyS => "s",
+        Code::KeyT => "t",
+        Code::KeyU => "u",
+        Code::KeyV => "v",
+        Code::KeyW => "w",
+        Code::KeyX => "x",
+        Code::KeyY => "y",
+        Code::KeyZ => "z",
+
+        Code::Digit0 => "0",
+        Code::Digit1 => "1",
+        Code::Digit2 => "2",
+        Code::Digit3 => "3",
+        Code::Digit4 => "4",
+        Code::Digit5 => "5",
+        Code::Digit6 => "6",
+        Code::Digit7 => "7",
+        Code::Digit8 => "8",
+        Code::Digit9 => "9",
+
+        Code::F1 => "f1",
+        Code::F2 => "f2",
+        Code::F3 => "f3",
+        Code::F4 => "f4",
+        Code::F5 => "f5",
+        Code::F6 => "f6",
+        Code::F7 => "f7",
+        Code::F8 => "f8",
+        Code::F9 => "f9",
+        Code::F10 => "f10",
+        Code::F11 => "f11",
+        Code::F12 => "f12",
+
+        Code::Space => "space",
+        Code::Enter => "enter",
+        Code::Tab => "tab",
+        Code::Escape => "escape",
+        Code::Backspace => "backspace",
+        Code::Delete => "delete",
+        Code::Insert => "insert",
+        Code::Home => "home",
+        Code::End => "end",
+        Code::PageUp => "pageup",
+        Code::PageDown => "pagedown",
+
+        Code::ArrowUp => "up",
+        Code::ArrowDown => "down",
+        Code::ArrowLeft => "left",
+        Code::ArrowRight => "right",
+
+        Code::Numpad0 => "num0",
+        Code::Numpad1 => "num1",
+        Code::Numpad2 => "num2",
+        Code::Numpad3 => "num3",
+        Code::Numpad4 => "num4",
+        Code::Numpad5 => "num5",
+        Code::Numpad6 => "num6",
+        Code::Numpad7 => "num7",
+        Code::Numpad8 => "num8",
+        Code::Numpad9 => "num9",
+        Code::NumpadAdd => "numadd",
+        Code::NumpadSubtract => "numsubtract",
+        Code::NumpadMultiply => "nummultiply",
+        Code::NumpadDivide => "numdivide",
+        Code::NumpadEnter => "numenter",
+
+        Code::Minus => "minus",
+        Code::Equal => "equal",
+        Code::BracketLeft => "bracketleft",
+        Code::BracketRight => "bracketright",
+        Code::Semicolon => "semicolon",
+        Code::Quote => "quote",
+        Code::Comma => "comma",
+        Code::Period => "period",
+        Code::Slash => "slash",
+        Code::Backquote => "backquote",
+        Code::Backslash => "backslash",
+
+        Code::CapsLock => "capslock",
+        Code::PrintScreen => "printscreen",
+        Code::ScrollLock => "scrolllock",
+        Code::Pause => "pause",
+        Code::ContextMenu => "contextmenu",
+
+        Code::F13 => "f13",
+        Code::F14 => "f14",
+        Code::F15 => "f15",
+        Code::F16 => "f16",
+        Code::F17 => "f17",
+        Code::F18 => "f18",
+        Code::F19 => "f19",
+        Code::F20 => "f20",
+        Code::F21 => "f21",
+        Code::F22 => "f22",
+        Code::F23 => "f23",
+        Code::F24 => "f24",
+
+        other => return Err(anyhow::anyhow!("Unsupported key code: {:?}", other)),
+    })
+}
+
 fn parse_key_code(key: &str) -> Result<global_hotkey::hotkey::Code> {
     use global_hotkey::hotkey::Code;
 
@@ -143,7 +702,7 @@ fn parse_key_code(key: &str) -> Result<global_hotkey::hotkey::Code> {
         "x" => Code::KeyX,
         "y" => Code::KeyY,
         "z" => Code::KeyZ,
-        
+
         // Numbers
         "0" => Code::Digit0,
         "1" => Code::Digit1,
@@ -155,7 +714,7 @@ fn parse_key_code(key: &str) -> Result<global_hotkey::hotkey::Code> {
         "7" => Code::Digit7,
         "8" => Code::Digit8,
         "9" => Code::Digit9,
-        
+
         // Function keys
         "f1" => Code::F1,
         "f2" => Code::F2,
@@ -169,7 +728,7 @@ fn parse_key_code(key: &str) -> Result<global_hotkey::hotkey::Code> {
         "f10" => Code::F10,
         "f11" => Code::F11,
         "f12" => Code::F12,
-        
+
         // Special keys
         "space" => Code::Space,
         "enter" | "return" => Code::Enter,
@@ -182,15 +741,212 @@ fn parse_key_code(key: &str) -> Result<global_hotkey::hotkey::Code> {
         "end" => Code::End,
         "pageup" => Code::PageUp,
         "pagedown" => Code::PageDown,
-        
+
         // Arrow keys
         "up" | "arrowup" => Code::ArrowUp,
         "down" | "arrowdown" => Code::ArrowDown,
         "left" | "arrowleft" => Code::ArrowLeft,
         "right" | "arrowright" => Code::ArrowRight,
-        
+
+        // Numpad digits and operators
+        "num0" | "numpad0" => Code::Numpad0,
+        "num1" | "numpad1" => Code::Numpad1,
+        "num2" | "numpad2" => Code::Numpad2,
+        "num3" | "numpad3" => Code::Numpad3,
+        "num4" | "numpad4" => Code::Numpad4,
+        "num5" | "numpad5" => Code::Numpad5,
+        "num6" | "numpad6" => Code::Numpad6,
+        "num7" | "numpad7" => Code::Numpad7,
+        "num8" | "numpad8" => Code::Numpad8,
+        "num9" | "numpad9" => Code::Numpad9,
+        "numadd" | "kp_add" | "numpadadd" | "plus" => Code::NumpadAdd,
+        "numsubtract" | "kp_subtract" | "numpadsubtract" | "numminus" => Code::NumpadSubtract,
+        "nummultiply" | "kp_multiply" | "numpadmultiply" | "numstar" => Code::NumpadMultiply,
+        "numdivide" | "kp_divide" | "numpaddivide" | "numslash" => Code::NumpadDivide,
+        "numenter" | "kp_enter" | "numpadenter" => Code::NumpadEnter,
+
+        // Punctuation
+        "minus" | "hyphen" | "-" => Code::Minus,
+        "equal" | "equals" | "=" => Code::Equal,
+        "bracketleft" | "leftbracket" | "[" => Code::BracketLeft,
+        "bracketright" | "rightbracket" | "]" => Code::BracketRight,
+        "semicolon" | ";" => Code::Semicolon,
+        "quote" | "'" => Code::Quote,
+        "comma" => Code::Comma,
+        "period" | "dot" | "." => Code::Period,
+        "slash" | "/" => Code::Slash,
+        "backquote" | "grave" | "backtick" | "`" => Code::Backquote,
+        "backslash" | "\\" => Code::Backslash,
+
+        // Lock/system keys
+        "capslock" => Code::CapsLock,
+        "printscreen" | "prtsc" | "prtscr" => Code::PrintScreen,
+        "scrolllock" => Code::ScrollLock,
+        "pause" | "pausebreak" | "break" => Code::Pause,
+        "contextmenu" | "menu" | "apps" => Code::ContextMenu,
+
+        // Extended function keys
+        "f13" => Code::F13,
+        "f14" => Code::F14,
+        "f15" => Code::F15,
+        "f16" => Code::F16,
+        "f17" => Code::F17,
+        "f18" => Code::F18,
+        "f19" => Code::F19,
+        "f20" => Code::F20,
+        "f21" => Code::F21,
+        "f22" => Code::F22,
+        "f23" => Code::F23,
+        "f24" => Code::F24,
+
         _ => return Err(anyhow::anyhow!("Unsupported key: {}", key)),
     };
 
     Ok(code)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn binding(chain: Vec<global_hotkey::hotkey::HotKey>, trigger: Trigger) -> ChainBinding {
+        ChainBinding {
+            chain,
+            action: Action::Toggle,
+            trigger,
+            cooldown_ms: 0,
+        }
+    }
+
+    #[test]
+    fn test_advance_sequence_completes_single_step_chain() {
+        let ctrl_k = parse_hotkey("ctrl+k").unwrap();
+        let bindings = vec![binding(vec![ctrl_k], Trigger::Press)];
+
+        let (next, completed) =
+            advance_sequence(&bindings, &[0], 0, ctrl_k.id(), HotKeyState::Pressed);
+        assert!(next.is_empty());
+        assert_eq!(completed, Some(0));
+    }
+
+    #[test]
+    fn test_advance_sequence_requires_every_step_in_order() {
+        let ctrl_k = parse_hotkey("ctrl+k").unwrap();
+        let p = parse_hotkey("p").unwrap();
+        let bindings = vec![binding(vec![ctrl_k, p], Trigger::Press)];
+
+        let (next, completed) =
+            advance_sequence(&bindings, &[0], 0, ctrl_k.id(), HotKeyState::Pressed);
+        assert_eq!(next, vec![0]);
+        assert_eq!(completed, None);
+
+        let (next, completed) = advance_sequence(&bindings, &next, 1, p.id(), HotKeyState::Pressed);
+        assert!(next.is_empty());
+        assert_eq!(completed, Some(0));
+    }
+
+    #[test]
+    fn test_advance_sequence_resets_on_non_matching_key() {
+        let ctrl_k = parse_hotkey("ctrl+k").unwrap();
+        let p = parse_hotkey("p").unwrap();
+        let q = parse_hotkey("q").unwrap();
+        let bindings = vec![binding(vec![ctrl_k, p], Trigger::Press)];
+
+        let (next, _) = advance_sequence(&bindings, &[0], 0, ctrl_k.id(), HotKeyState::Pressed);
+        let (next, completed) = advance_sequence(&bindings, &next, 1, q.id(), HotKeyState::Pressed);
+        assert!(next.is_empty());
+        assert_eq!(completed, None);
+    }
+
+    #[test]
+    fn test_repeat_of_previous_step_chord_is_not_a_reset() {
+        let ctrl_k = parse_hotkey("ctrl+k").unwrap();
+        let p = parse_hotkey("p").unwrap();
+        let bindings = vec![binding(vec![ctrl_k, p], Trigger::Press)];
+
+        let (candidates, _) =
+            advance_sequence(&bindings, &[0], 0, ctrl_k.id(), HotKeyState::Pressed);
+        assert_eq!(candidates, vec![0]);
+
+        // Holding ctrl+k down before pressing p key-repeats a `Pressed`
+        // event for ctrl+k while step == 1 (expecting p). That must be
+        // recognized as a repeat, not a different key.
+        assert!(is_repeat_of_previous_step(
+            &bindings,
+            &candidates,
+            1,
+            ctrl_k.id(),
+            HotKeyState::Pressed,
+        ));
+
+        // A genuinely different key at the same step is still a reset.
+        let q = parse_hotkey("q").unwrap();
+        assert!(!is_repeat_of_previous_step(
+            &bindings,
+            &candidates,
+            1,
+            q.id(),
+            HotKeyState::Pressed,
+        ));
+
+        // The chain still completes normally once p actually fires.
+        let (next, completed) =
+            advance_sequence(&bindings, &candidates, 1, p.id(), HotKeyState::Pressed);
+        assert!(next.is_empty());
+        assert_eq!(completed, Some(0));
+    }
+
+    #[test]
+    fn test_advance_sequence_tracks_concurrent_prefixes() {
+        let ctrl_k = parse_hotkey("ctrl+k").unwrap();
+        let p = parse_hotkey("p").unwrap();
+        let q = parse_hotkey("q").unwrap();
+        let bindings = vec![
+            binding(vec![ctrl_k, p], Trigger::Press),
+            binding(vec![ctrl_k, q], Trigger::Press),
+        ];
+
+        let (next, completed) =
+            advance_sequence(&bindings, &[0, 1], 0, ctrl_k.id(), HotKeyState::Pressed);
+        assert_eq!(next, vec![0, 1]);
+        assert_eq!(completed, None);
+
+        let (remaining, completed) = advance_sequence(&bindings, &next, 1, q.id(), HotKeyState::Pressed);
+        assert!(remaining.is_empty());
+        assert_eq!(completed, Some(1));
+    }
+
+    #[test]
+    fn test_parse_and_format_hotkey_chain_round_trip() {
+        let chain = parse_hotkey_chain("ctrl+k, p").unwrap();
+        assert_eq!(chain.len(), 2);
+
+        let label = format_hotkey_chain(&chain).unwrap();
+        assert_eq!(label, "ctrl+k, p");
+
+        let reparsed = parse_hotkey_chain(&label).unwrap();
+        assert_eq!(
+            chain.iter().map(|h| h.id()).collect::<Vec<_>>(),
+            reparsed.iter().map(|h| h.id()).collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn test_parse_hotkey_chain_single_step_matches_parse_hotkey() {
+        let single = parse_hotkey_chain("ctrl+alt+r").unwrap();
+        assert_eq!(single.len(), 1);
+        assert_eq!(single[0].id(), parse_hotkey("ctrl+alt+r").unwrap().id());
+    }
+
+    #[test]
+    fn test_parse_hotkey_chain_step_naming_comma_key_by_its_long_alias() {
+        // The chain separator is a bare `,`, so a step binding the Comma key
+        // must spell it out as "comma" rather than the punctuation alias
+        // (which would be indistinguishable from the separator itself).
+        let chain = parse_hotkey_chain("ctrl+comma, p").unwrap();
+        assert_eq!(chain.len(), 2);
+        assert_eq!(chain[0].id(), parse_hotkey("ctrl+comma").unwrap().id());
+
+        assert!(parse_key_code(",").is_err());
+    }
+}
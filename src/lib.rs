@@ -42,14 +42,27 @@
 //! }
 //! ```
 
+pub mod condition;
 pub mod config;
+pub mod control_socket;
 pub mod error;
 pub mod global_hotkey;
 pub mod key_sender;
 pub mod process_finder;
+pub mod process_matcher;
+pub mod process_state;
+pub mod scheduler;
 
+pub use condition::Condition;
 pub use config::Config;
+pub use control_socket::start_control_socket;
 pub use error::{PksError, Result};
-pub use global_hotkey::HotkeyManager;
+pub use global_hotkey::{
+    format_hotkey, format_hotkey_chain, parse_hotkey, parse_hotkey_chain, Action, HotkeyBinding,
+    HotkeyManager, Trigger,
+};
 pub use key_sender::KeySender;
 pub use process_finder::ProcessFinder;
+pub use process_matcher::ProcessMatcher;
+pub use process_state::{ProcessState, StateTracker, StateTransition};
+pub use scheduler::{IndependentKeyJob, Job, Scheduler, SequenceJob};
@@ -3,8 +3,9 @@
 //! This module provides functionality to find running processes by name
 //! and retrieve their window handles for key sending operations.
 
+use crate::process_matcher::{NameContains, ProcessMatcher};
 use anyhow::Result;
-use sysinfo::{ProcessesToUpdate, System};
+use sysinfo::{ProcessRefreshKind, ProcessesToUpdate, System};
 
 /// Finds processes by name and retrieves window identifiers.
 ///
@@ -50,15 +51,28 @@ impl ProcessFinder {
     }
 
     pub fn find_process_window(&mut self, process_name: &str) -> Result<Option<u64>> {
-        // Refresh all processes (new sysinfo 0.37+ API)
-        self.system.refresh_processes(ProcessesToUpdate::All, true);
+        self.find_process_window_matching(&NameContains {
+            needle: process_name.to_string(),
+        })
+    }
 
-        let process_name_lower = process_name.to_lowercase();
+    /// Like `find_process_window`, but selects the target with a caller-supplied
+    /// `ProcessMatcher` instead of the default case-insensitive substring match.
+    pub fn find_process_window_matching(
+        &mut self,
+        matcher: &dyn ProcessMatcher,
+    ) -> Result<Option<u64>> {
+        // `refresh_processes`'s default `ProcessRefreshKind` doesn't include
+        // command-line args, which `CmdlineContains` needs, so refresh with
+        // `with_cmd` explicitly here.
+        self.system.refresh_processes_specifics(
+            ProcessesToUpdate::All,
+            true,
+            ProcessRefreshKind::nothing().with_cmd(sysinfo::UpdateKind::Always),
+        );
 
         for (pid, process) in self.system.processes() {
-            // process.name() returns &OsStr, convert to string for comparison
-            let name = process.name().to_string_lossy().to_lowercase();
-            if name.contains(&process_name_lower) {
+            if matcher.matches(process) {
                 #[cfg(windows)]
                 {
                     // For Windows, we'll use a simpler approach - just return the PID as window ID
@@ -0,0 +1,73 @@
+//! Pluggable process matching strategies.
+//!
+//! `ProcessFinder` delegates to a `ProcessMatcher` to decide which running
+//! process is the automation target, instead of a hard-coded substring
+//! comparison on the process name. This makes targeting reliable across
+//! platforms where process names get truncated or collide (e.g. two games
+//! sharing a generic launcher executable name).
+
+use regex::Regex;
+use sysinfo::Process;
+
+/// Decides whether a given process is the automation target.
+pub trait ProcessMatcher {
+    fn matches(&self, process: &Process) -> bool;
+}
+
+/// Matches when the process name contains `needle` (case-insensitive).
+///
+/// This is the historical behavior of `ProcessFinder::find_process_window`.
+#[derive(Debug, Clone)]
+pub struct NameContains {
+    pub needle: String,
+}
+
+impl ProcessMatcher for NameContains {
+    fn matches(&self, process: &Process) -> bool {
+        process
+            .name()
+            .to_string_lossy()
+            .to_lowercase()
+            .contains(&self.needle.to_lowercase())
+    }
+}
+
+/// Matches when the process name equals `name` exactly (case-insensitive).
+#[derive(Debug, Clone)]
+pub struct NameExact {
+    pub name: String,
+}
+
+impl ProcessMatcher for NameExact {
+    fn matches(&self, process: &Process) -> bool {
+        process.name().to_string_lossy().to_lowercase() == self.name.to_lowercase()
+    }
+}
+
+/// Matches when the process name matches a regular expression.
+#[derive(Debug, Clone)]
+pub struct NameRegex {
+    pub pattern: Regex,
+}
+
+impl ProcessMatcher for NameRegex {
+    fn matches(&self, process: &Process) -> bool {
+        self.pattern.is_match(&process.name().to_string_lossy())
+    }
+}
+
+/// Matches when any command-line argument contains `needle` (case-insensitive).
+#[derive(Debug, Clone)]
+pub struct CmdlineContains {
+    pub needle: String,
+}
+
+impl ProcessMatcher for CmdlineContains {
+    fn matches(&self, process: &Process) -> bool {
+        let needle = self.needle.to_lowercase();
+        process
+            .cmd()
+            .iter()
+            .any(|arg| arg.to_string_lossy().to_lowercase().contains(&needle))
+    }
+}
@@ -0,0 +1,93 @@
+//! Process lifecycle tracking.
+//!
+//! `ProcessFinder::find_process_window` is a one-shot lookup, so a caller's
+//! sender loop has no way to notice that the target process exited or came
+//! back. `StateTracker` polls a `ProcessFinder` on an interval and reports
+//! `Started`/`Stopped` transitions, so the loop can suspend key-sending
+//! while the target is gone (instead of erroring out with `KeySendFailed`)
+//! and resume once a new instance reappears.
+
+use crate::process_finder::ProcessFinder;
+use crate::process_matcher::ProcessMatcher;
+use anyhow::Result;
+
+/// The target process as last observed by a `StateTracker`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ProcessState {
+    /// The target is running with this PID/window id.
+    Running(u64),
+    /// The target is not currently running.
+    Stopped,
+}
+
+/// A change in `ProcessState` between two polls.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StateTransition {
+    /// The target process appeared, or a new instance replaced the old one.
+    Started(u64),
+    /// The target process that was previously running has exited.
+    Stopped,
+}
+
+/// Polls a `ProcessFinder` and reports transitions relative to the previous poll.
+///
+/// # Example
+///
+/// ```no_run
+/// use process_key_sender::{ProcessFinder, StateTracker, StateTransition};
+/// use process_key_sender::process_matcher::NameContains;
+///
+/// let matcher = NameContains { needle: "notepad".to_string() };
+/// let mut tracker = StateTracker::new(ProcessFinder::new());
+///
+/// // In the sender loop, poll once per tick and react to transitions:
+/// if let Some(transition) = tracker.poll(&matcher).unwrap() {
+///     match transition {
+///         StateTransition::Started(pid) => println!("target started, pid {pid}"),
+///         StateTransition::Stopped => println!("target stopped, suspending"),
+///     }
+/// }
+/// ```
+pub struct StateTracker {
+    finder: ProcessFinder,
+    state: ProcessState,
+}
+
+impl StateTracker {
+    pub fn new(finder: ProcessFinder) -> Self {
+        Self {
+            finder,
+            state: ProcessState::Stopped,
+        }
+    }
+
+    /// The state as of the last `poll` call (`Stopped` before the first poll).
+    pub fn state(&self) -> ProcessState {
+        self.state
+    }
+
+    /// Re-checks the target process and returns a transition if the state
+    /// changed since the previous poll.
+    pub fn poll(&mut self, matcher: &dyn ProcessMatcher) -> Result<Option<StateTransition>> {
+        let found = self.finder.find_process_window_matching(matcher)?;
+
+        let new_state = match found {
+            Some(pid) => ProcessState::Running(pid),
+            None => ProcessState::Stopped,
+        };
+
+        let transition = match (self.state, new_state) {
+            (ProcessState::Stopped, ProcessState::Running(pid)) => {
+                Some(StateTransition::Started(pid))
+            }
+            (ProcessState::Running(old_pid), ProcessState::Running(pid)) if old_pid != pid => {
+                Some(StateTransition::Started(pid))
+            }
+            (ProcessState::Running(_), ProcessState::Stopped) => Some(StateTransition::Stopped),
+            _ => None,
+        };
+
+        self.state = new_state;
+        Ok(transition)
+    }
+}
@@ -0,0 +1,307 @@
+//! Unified interval scheduler.
+//!
+//! Key sequences and independent keys used to run through two separate
+//! timing loops, which could drift or contend with each other for the
+//! window's focus. `Scheduler` unifies them: every timed unit of work is a
+//! `Job` popped off a min-heap ordered by next fire time, slept on, fired,
+//! and (unless it's finished) rescheduled and pushed back onto the heap.
+
+use crate::condition::ResourceGate;
+use crate::config::{IndependentKey, KeyAction};
+use crate::error::{PksError, Result};
+use crate::key_sender::KeySender;
+use crate::process_matcher::ProcessMatcher;
+use crate::process_state::{ProcessState, StateTracker};
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+use std::time::{Duration, Instant};
+
+pub type JobId = u64;
+
+/// A unit of scheduled work: an independent key, or a whole key sequence.
+pub trait Job {
+    /// When this job should next fire.
+    fn next_fire(&self) -> Instant;
+
+    /// Fires the job against `window_id` using `sender`.
+    fn fire(&mut self, sender: &KeySender, window_id: u64) -> Result<()>;
+
+    /// Computes this job's next `next_fire` time relative to `now`.
+    /// Returns `false` if the job is done and should not be rescheduled
+    /// (e.g. a finite sequence that has run its `repeat_count`).
+    fn reschedule(&mut self, now: Instant) -> bool;
+
+    /// Pushes `next_fire` out to retry later without otherwise advancing
+    /// the job, for when a fire was skipped rather than performed (e.g. the
+    /// target process was stopped). Unlike `reschedule`, this never mutates
+    /// sequence progress or repeat-count bookkeeping.
+    fn defer(&mut self, now: Instant);
+}
+
+/// Ties the scheduler's firing to the target process's lifecycle.
+///
+/// While the tracked process is `Stopped`, due jobs are skipped (not fired)
+/// instead of erroring against a dead window; once it reappears, possibly
+/// under a new pid, jobs resume against that pid. `auto_resume = false`
+/// reproduces the crate's original one-shot behavior: the first time the
+/// target is seen stopped, the scheduler halts for good.
+struct ProcessWatch {
+    tracker: StateTracker,
+    matcher: Box<dyn ProcessMatcher>,
+    auto_resume: bool,
+    halted: bool,
+}
+
+/// Runs registered `Job`s in fire-time order.
+///
+/// Independent keys become self-rescheduling interval jobs; a key sequence
+/// becomes one stateful job that advances an internal step index and only
+/// reschedules the whole sequence after its last step, honoring
+/// `loop_sequence`/`repeat_count`.
+pub struct Scheduler {
+    jobs: Vec<Box<dyn Job>>,
+    heap: BinaryHeap<Reverse<(Instant, JobId)>>,
+    process_watch: Option<ProcessWatch>,
+}
+
+impl Default for Scheduler {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Scheduler {
+    pub fn new() -> Self {
+        Self {
+            jobs: Vec::new(),
+            heap: BinaryHeap::new(),
+            process_watch: None,
+        }
+    }
+
+    /// Registers `job`, scheduling it at its initial `next_fire` time.
+    pub fn add_job(&mut self, job: Box<dyn Job>) -> JobId {
+        let id = self.jobs.len() as JobId;
+        let fire_at = job.next_fire();
+        self.jobs.push(job);
+        self.heap.push(Reverse((fire_at, id)));
+        id
+    }
+
+    /// Arms process lifecycle awareness: before firing a due job, `tick`
+    /// polls `matcher` through `tracker` and skips the fire while the
+    /// target is stopped, per `auto_resume` (see `ProcessWatch`).
+    pub fn watch_process(
+        &mut self,
+        tracker: StateTracker,
+        matcher: Box<dyn ProcessMatcher>,
+        auto_resume: bool,
+    ) {
+        self.process_watch = Some(ProcessWatch {
+            tracker,
+            matcher,
+            auto_resume,
+            halted: false,
+        });
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.heap.is_empty()
+    }
+
+    /// Pops the earliest-due job, sleeps until it's due, fires it, then
+    /// reschedules it if it isn't finished. Returns `false` once no jobs
+    /// remain in the heap, or once a watched process has stopped for good
+    /// with `auto_resume` disabled.
+    pub fn tick(&mut self, sender: &KeySender, window_id: u64) -> Result<bool> {
+        if matches!(&self.process_watch, Some(watch) if watch.halted) {
+            return Ok(false);
+        }
+
+        let Some(Reverse((fire_at, id))) = self.heap.pop() else {
+            return Ok(false);
+        };
+
+        let now = Instant::now();
+        if fire_at > now {
+            std::thread::sleep(fire_at - now);
+        }
+
+        if let Some(watch) = self.process_watch.as_mut() {
+            watch
+                .tracker
+                .poll(watch.matcher.as_ref())
+                .map_err(|e| PksError::window(format!("process lifecycle poll failed: {}", e)))?;
+
+            match watch.tracker.state() {
+                ProcessState::Stopped => {
+                    if !watch.auto_resume {
+                        watch.halted = true;
+                        return Ok(false);
+                    }
+
+                    let job = &mut self.jobs[id as usize];
+                    job.defer(Instant::now());
+                    self.heap.push(Reverse((job.next_fire(), id)));
+                    return Ok(true);
+                }
+                ProcessState::Running(pid) => {
+                    return self.fire_and_reschedule(sender, pid, id);
+                }
+            }
+        }
+
+        self.fire_and_reschedule(sender, window_id, id)
+    }
+
+    fn fire_and_reschedule(&mut self, sender: &KeySender, window_id: u64, id: JobId) -> Result<bool> {
+        let job = &mut self.jobs[id as usize];
+        job.fire(sender, window_id)?;
+
+        let now = Instant::now();
+        if job.reschedule(now) {
+            self.heap.push(Reverse((job.next_fire(), id)));
+        }
+
+        Ok(true)
+    }
+
+    /// Runs `tick` in a loop until no jobs remain.
+    pub fn run(&mut self, sender: &KeySender, window_id: u64) -> Result<()> {
+        while self.tick(sender, window_id)? {}
+        Ok(())
+    }
+}
+
+/// A `Job` for a single independently-timed key: fires on its own interval
+/// for as long as it stays registered with the scheduler.
+pub struct IndependentKeyJob {
+    key: IndependentKey,
+    gate: ResourceGate,
+    next_fire: Instant,
+}
+
+impl IndependentKeyJob {
+    pub fn new(key: IndependentKey) -> Self {
+        Self {
+            next_fire: Instant::now() + key.interval,
+            key,
+            gate: ResourceGate::new(),
+        }
+    }
+}
+
+impl Job for IndependentKeyJob {
+    fn next_fire(&self) -> Instant {
+        self.next_fire
+    }
+
+    fn fire(&mut self, sender: &KeySender, window_id: u64) -> Result<()> {
+        let should_fire = match &self.key.when {
+            Some(condition) => self.gate.check(window_id, condition),
+            None => true,
+        };
+
+        if should_fire {
+            sender.send_key_to_window(window_id, &self.key.key)?;
+        }
+
+        Ok(())
+    }
+
+    fn reschedule(&mut self, now: Instant) -> bool {
+        self.next_fire = now + self.key.interval;
+        true
+    }
+
+    fn defer(&mut self, now: Instant) {
+        self.next_fire = now + self.key.interval;
+    }
+}
+
+/// A `Job` for a key sequence: advances an internal step index each fire,
+/// and only reschedules the whole sequence after its last step, honoring
+/// `loop_sequence`/`repeat_count`.
+pub struct SequenceJob {
+    steps: Vec<KeyAction>,
+    index: usize,
+    loop_sequence: bool,
+    repeat_count: u32,
+    completed_runs: u32,
+    gate: ResourceGate,
+    next_fire: Instant,
+}
+
+impl SequenceJob {
+    pub fn new(steps: Vec<KeyAction>, loop_sequence: bool, repeat_count: u32) -> Self {
+        Self {
+            next_fire: Instant::now(),
+            steps,
+            index: 0,
+            loop_sequence,
+            repeat_count,
+            completed_runs: 0,
+            gate: ResourceGate::new(),
+        }
+    }
+
+    /// How many times the sequence has run to completion so far.
+    pub fn completed_runs(&self) -> u32 {
+        self.completed_runs
+    }
+}
+
+impl Job for SequenceJob {
+    fn next_fire(&self) -> Instant {
+        self.next_fire
+    }
+
+    fn fire(&mut self, sender: &KeySender, window_id: u64) -> Result<()> {
+        let Some(step) = self.steps.get(self.index) else {
+            return Ok(());
+        };
+
+        let should_fire = match &step.when {
+            Some(condition) => self.gate.check(window_id, condition),
+            None => true,
+        };
+
+        if should_fire {
+            sender.send_key_to_window(window_id, &step.key)?;
+        }
+
+        Ok(())
+    }
+
+    fn reschedule(&mut self, now: Instant) -> bool {
+        let Some(step) = self.steps.get(self.index) else {
+            return false;
+        };
+        let interval = step.interval_after;
+        self.index += 1;
+
+        if self.index >= self.steps.len() {
+            self.index = 0;
+            self.completed_runs += 1;
+
+            if !self.loop_sequence && self.completed_runs >= self.repeat_count.max(1) {
+                return false;
+            }
+        }
+
+        self.next_fire = now + interval;
+        true
+    }
+
+    fn defer(&mut self, now: Instant) {
+        // Retry at the current step's own cadence; unlike `reschedule`, this
+        // never advances `index` or touches `completed_runs`, so a sequence
+        // deferred because the watched process is stopped resumes from the
+        // same step once it comes back, instead of silently "completing".
+        let interval = self
+            .steps
+            .get(self.index)
+            .map_or(Duration::from_millis(0), |step| step.interval_after);
+        self.next_fire = now + interval;
+    }
+}
@@ -145,6 +145,82 @@ fn test_config_file_operations() -> Result<()> {
     Ok(())
 }
 
+#[test]
+fn test_config_deserializes_regex_match_spec() -> Result<()> {
+    use process_key_sender::config::MatchSpec;
+
+    let json_content = r#"
+    {
+        "process_name": "fallback.exe",
+        "independent_keys": [{"key": "space", "interval": "1s"}],
+        "match": {"regex": "^Revolution Idle"}
+    }
+    "#;
+
+    let config: Config = serde_json::from_str(json_content).unwrap();
+    assert!(
+        matches!(config.process_match, Some(MatchSpec::Regex(ref pattern)) if pattern == "^Revolution Idle")
+    );
+
+    // The spec should build into a usable matcher even though nothing on
+    // this machine is named "Revolution Idle".
+    let matcher = config.matcher()?;
+    let mut finder = ProcessFinder::new();
+    assert_eq!(finder.find_process_window_matching(matcher.as_ref())?, None);
+
+    Ok(())
+}
+
+#[test]
+fn test_match_spec_name_exact_and_cmdline_contains_against_a_real_process() {
+    use process_key_sender::config::MatchSpec;
+
+    // A uniquely-valued duration doubles as a marker in the child's cmdline
+    // without tripping `sleep`'s argument parsing.
+    let mut child = std::process::Command::new("sleep")
+        .arg("2.024601")
+        .spawn()
+        .expect("failed to spawn sleep for test");
+    std::thread::sleep(Duration::from_millis(200));
+
+    let exact = MatchSpec::Exact("sleep".to_string()).to_matcher().unwrap();
+    let mut finder = ProcessFinder::new();
+    assert_eq!(
+        finder.find_process_window_matching(exact.as_ref()).unwrap(),
+        Some(child.id() as u64)
+    );
+
+    let cmdline = MatchSpec::CmdlineContains("2.024601".to_string())
+        .to_matcher()
+        .unwrap();
+    assert_eq!(
+        finder
+            .find_process_window_matching(cmdline.as_ref())
+            .unwrap(),
+        Some(child.id() as u64)
+    );
+
+    let no_match = MatchSpec::CmdlineContains("definitely-not-an-arg-xyz".to_string())
+        .to_matcher()
+        .unwrap();
+    assert_eq!(
+        finder.find_process_window_matching(no_match.as_ref()).unwrap(),
+        None
+    );
+
+    let _ = child.kill();
+    let _ = child.wait();
+}
+
+#[test]
+fn test_match_spec_invalid_regex_is_config_validation_error() {
+    use process_key_sender::config::MatchSpec;
+    use process_key_sender::PksError;
+
+    let result = MatchSpec::Regex("(".to_string()).to_matcher();
+    assert!(matches!(result, Err(PksError::ConfigValidation(_))));
+}
+
 #[test]
 fn test_duration_parsing_edge_cases() {
     // Valid cases
@@ -160,6 +236,21 @@ fn test_duration_parsing_edge_cases() {
     assert!(parse_duration("-1000ms").is_err());
 }
 
+#[test]
+fn test_byte_size_parsing_edge_cases() {
+    use process_key_sender::condition::parse_byte_size;
+
+    assert_eq!(parse_byte_size("100").unwrap(), 100);
+    assert_eq!(parse_byte_size("512b").unwrap(), 512);
+    assert_eq!(parse_byte_size("1KB").unwrap(), 1024);
+    assert_eq!(parse_byte_size("512MB").unwrap(), 512 * 1024 * 1024);
+    assert_eq!(parse_byte_size("2GB").unwrap(), 2 * 1024 * 1024 * 1024);
+
+    assert!(parse_byte_size("").is_err());
+    assert!(parse_byte_size("abc").is_err());
+    assert!(parse_byte_size("-5MB").is_err());
+}
+
 #[test]
 fn test_config_validation_errors() {
     // Empty process name
@@ -173,6 +264,8 @@ fn test_config_validation_errors() {
         loop_sequence: true,
         repeat_count: 0,
         restore_focus: true,
+        process_match: None,
+        auto_resume: true,
     };
 
     assert!(config.validate().is_err());
@@ -187,6 +280,7 @@ fn test_config_validation_errors() {
         .push(process_key_sender::config::IndependentKey {
             key: "space".to_string(),
             interval: Duration::from_millis(1000),
+            when: None,
         });
     config.max_retries = 0;
     assert!(config.validate().is_err());
@@ -293,6 +387,38 @@ fn test_mixed_duration_formats() {
     assert!(config.validate().is_ok());
 }
 
+#[test]
+fn test_independent_key_with_resource_condition() {
+    let json = r#"
+    {
+        "process_name": "game.exe",
+        "independent_keys": [
+            {
+                "key": "r",
+                "interval": "1s",
+                "when": {"cpu_below": 5.0}
+            },
+            {
+                "key": "a",
+                "interval": "1s",
+                "when": {"mem_above": "512MB"}
+            }
+        ]
+    }
+    "#;
+
+    let config: Config = serde_json::from_str(json).unwrap();
+
+    assert_eq!(
+        config.independent_keys[0].when,
+        Some(process_key_sender::Condition::CpuBelow(5.0))
+    );
+    assert_eq!(
+        config.independent_keys[1].when,
+        Some(process_key_sender::Condition::MemAbove(512 * 1024 * 1024))
+    );
+}
+
 // ProcessFinder tests
 
 #[test]
@@ -318,6 +444,34 @@ fn test_process_finder_nonexistent_process() {
     assert!(result.unwrap().is_none());
 }
 
+// StateTracker tests
+
+#[test]
+fn test_state_tracker_starts_stopped() {
+    use process_key_sender::{ProcessState, StateTracker};
+
+    let tracker = StateTracker::new(ProcessFinder::new());
+    assert_eq!(tracker.state(), ProcessState::Stopped);
+}
+
+#[test]
+fn test_state_tracker_no_transition_while_absent() {
+    use process_key_sender::config::MatchSpec;
+    use process_key_sender::StateTracker;
+
+    let mut tracker = StateTracker::new(ProcessFinder::new());
+    let matcher = MatchSpec::Contains("nonexistent_process_xyz_123456".to_string())
+        .to_matcher()
+        .unwrap();
+
+    let transition = tracker.poll(matcher.as_ref()).unwrap();
+    assert!(transition.is_none());
+
+    // Polling again with the target still absent should stay quiet.
+    let transition = tracker.poll(matcher.as_ref()).unwrap();
+    assert!(transition.is_none());
+}
+
 // KeySender tests
 
 #[test]
@@ -327,6 +481,7 @@ fn test_key_sender_creation() {
 }
 
 #[test]
+#[allow(clippy::drop_non_drop)]
 fn test_key_sender_clone() {
     let sender = KeySender::new().unwrap();
     let sender2 = sender.clone();
@@ -395,6 +550,7 @@ fn test_config_save_load_roundtrip() -> Result<()> {
         key_sequence: vec![process_key_sender::config::KeyAction {
             key: "space".to_string(),
             interval_after: Duration::from_millis(1500),
+            when: None,
         }],
         independent_keys: vec![],
         max_retries: 15,
@@ -403,6 +559,8 @@ fn test_config_save_load_roundtrip() -> Result<()> {
         loop_sequence: false,
         repeat_count: 5,
         restore_focus: false,
+        process_match: None,
+        auto_resume: true,
     };
 
     // Save
@@ -429,6 +587,316 @@ fn test_config_save_load_roundtrip() -> Result<()> {
     Ok(())
 }
 
+// Scheduler tests
+
+#[test]
+fn test_independent_key_job_reschedules_on_its_interval() {
+    use process_key_sender::config::IndependentKey;
+    use process_key_sender::{IndependentKeyJob, Job};
+    use std::time::Instant;
+
+    let sender = KeySender::new().unwrap();
+    let mut job = IndependentKeyJob::new(IndependentKey {
+        key: "r".to_string(),
+        interval: Duration::from_millis(50),
+        when: None,
+    });
+
+    let first_fire = job.next_fire();
+    job.fire(&sender, 1).unwrap();
+    assert!(job.reschedule(Instant::now()));
+    assert!(job.next_fire() > first_fire);
+}
+
+#[test]
+fn test_sequence_job_stops_after_repeat_count() {
+    use process_key_sender::config::KeyAction;
+    use process_key_sender::{Job, SequenceJob};
+    use std::time::Instant;
+
+    let sender = KeySender::new().unwrap();
+    let steps = vec![
+        KeyAction {
+            key: "1".to_string(),
+            interval_after: Duration::from_millis(10),
+            when: None,
+        },
+        KeyAction {
+            key: "2".to_string(),
+            interval_after: Duration::from_millis(10),
+            when: None,
+        },
+    ];
+    let mut job = SequenceJob::new(steps, false, 2);
+
+    // The first three steps (1.5 passes through the two-step sequence) keep
+    // rescheduling...
+    for _ in 0..3 {
+        job.fire(&sender, 1).unwrap();
+        assert!(job.reschedule(Instant::now()));
+    }
+
+    // ...and the step that completes the second pass stops the job for good.
+    job.fire(&sender, 1).unwrap();
+    assert!(!job.reschedule(Instant::now()));
+    assert_eq!(job.completed_runs(), 2);
+}
+
+#[test]
+fn test_scheduler_runs_jobs_to_completion() {
+    use process_key_sender::config::KeyAction;
+    use process_key_sender::{Scheduler, SequenceJob};
+
+    let sender = KeySender::new().unwrap();
+    let mut scheduler = Scheduler::new();
+    scheduler.add_job(Box::new(SequenceJob::new(
+        vec![KeyAction {
+            key: "1".to_string(),
+            interval_after: Duration::from_millis(1),
+            when: None,
+        }],
+        false,
+        1,
+    )));
+
+    scheduler.run(&sender, 1).unwrap();
+    assert!(scheduler.is_empty());
+}
+
+#[test]
+fn test_scheduler_skips_jobs_while_watched_process_stopped() {
+    use process_key_sender::config::{IndependentKey, MatchSpec};
+    use process_key_sender::{IndependentKeyJob, ProcessFinder, Scheduler, StateTracker};
+
+    let sender = KeySender::new().unwrap();
+    let mut scheduler = Scheduler::new();
+    scheduler.add_job(Box::new(IndependentKeyJob::new(IndependentKey {
+        key: "space".to_string(),
+        interval: Duration::from_millis(1),
+        when: None,
+    })));
+
+    let matcher = MatchSpec::Contains("nonexistent_process_xyz_123456".to_string())
+        .to_matcher()
+        .unwrap();
+    scheduler.watch_process(StateTracker::new(ProcessFinder::new()), matcher, true);
+
+    // The target never appears, but with auto_resume enabled the scheduler
+    // keeps ticking (skipping fires) instead of erroring or finishing.
+    for _ in 0..5 {
+        assert!(scheduler.tick(&sender, 1).unwrap());
+    }
+    assert!(!scheduler.is_empty());
+}
+
+#[test]
+fn test_scheduler_defers_sequence_job_while_watched_process_stopped() {
+    use process_key_sender::config::{KeyAction, MatchSpec};
+    use process_key_sender::{ProcessFinder, Scheduler, SequenceJob, StateTracker};
+
+    let sender = KeySender::new().unwrap();
+    let mut scheduler = Scheduler::new();
+    scheduler.add_job(Box::new(SequenceJob::new(
+        vec![
+            KeyAction {
+                key: "1".to_string(),
+                interval_after: Duration::from_millis(1),
+                when: None,
+            },
+            KeyAction {
+                key: "2".to_string(),
+                interval_after: Duration::from_millis(1),
+                when: None,
+            },
+        ],
+        false,
+        1,
+    )));
+
+    let matcher = MatchSpec::Contains("nonexistent_process_xyz_123456".to_string())
+        .to_matcher()
+        .unwrap();
+    scheduler.watch_process(StateTracker::new(ProcessFinder::new()), matcher, true);
+
+    // The target never appears. If deferring while stopped advanced the
+    // sequence's step index like `reschedule` does, this one-shot,
+    // two-step sequence would "complete" and drop out of the heap after
+    // just two ticks, having sent zero keystrokes.
+    for _ in 0..5 {
+        assert!(scheduler.tick(&sender, 1).unwrap());
+    }
+    assert!(!scheduler.is_empty());
+}
+
+#[test]
+fn test_scheduler_halts_when_auto_resume_disabled() {
+    use process_key_sender::config::{IndependentKey, MatchSpec};
+    use process_key_sender::{IndependentKeyJob, ProcessFinder, Scheduler, StateTracker};
+
+    let sender = KeySender::new().unwrap();
+    let mut scheduler = Scheduler::new();
+    scheduler.add_job(Box::new(IndependentKeyJob::new(IndependentKey {
+        key: "space".to_string(),
+        interval: Duration::from_millis(1),
+        when: None,
+    })));
+
+    let matcher = MatchSpec::Contains("nonexistent_process_xyz_123456".to_string())
+        .to_matcher()
+        .unwrap();
+    scheduler.watch_process(StateTracker::new(ProcessFinder::new()), matcher, false);
+
+    // The target is stopped from the first tick, and auto_resume is
+    // disabled, so the scheduler halts for good rather than retrying.
+    assert!(!scheduler.tick(&sender, 1).unwrap());
+    assert!(!scheduler.tick(&sender, 1).unwrap());
+}
+
+#[test]
+fn test_config_toml_roundtrip() -> Result<()> {
+    let temp_dir = tempfile::tempdir()?;
+    let config_path = temp_dir.path().join("test_config.toml");
+
+    let original = Config {
+        process_name: "toml-app.exe".to_string(),
+        key_sequence: vec![],
+        independent_keys: vec![process_key_sender::config::IndependentKey {
+            key: "space".to_string(),
+            interval: Duration::from_secs(2),
+            when: None,
+        }],
+        max_retries: 7,
+        pause_hotkey: "ctrl+alt+t".to_string(),
+        verbose: true,
+        loop_sequence: true,
+        repeat_count: 0,
+        restore_focus: true,
+        process_match: None,
+        auto_resume: true,
+    };
+
+    original.save_to_file(config_path.to_str().unwrap())?;
+    let on_disk = std::fs::read_to_string(&config_path)?;
+    assert!(on_disk.contains("process_name"));
+
+    let loaded = Config::from_file(config_path.to_str().unwrap())?;
+    assert_eq!(loaded.process_name, original.process_name);
+    assert_eq!(loaded.independent_keys[0].key, "space");
+    assert_eq!(loaded.independent_keys[0].interval, Duration::from_secs(2));
+    assert_eq!(loaded.max_retries, 7);
+    assert_eq!(loaded.pause_hotkey, "ctrl+alt+t");
+
+    Ok(())
+}
+
+// HotkeyManager tests
+//
+// HotkeyManager wraps a platform global-hotkey manager that needs a real
+// display/event loop, so these tests stick to the parts that don't require
+// constructing one (binding config parsing and hotkey-string parsing and
+// formatting), matching how `HotkeyManager` itself is
+// untested elsewhere in this file.
+
+#[test]
+fn test_hotkey_binding_deserialization() {
+    use process_key_sender::{Action, HotkeyBinding, Trigger};
+
+    let json = r#"
+    [
+        {"hotkey": "ctrl+alt+r", "action": "pause"},
+        {"mode": "gaming", "hotkey": "ctrl+alt+b", "action": {"switch_profile": "burst"}}
+    ]
+    "#;
+
+    let bindings: Vec<HotkeyBinding> = serde_json::from_str(json).unwrap();
+    assert_eq!(bindings.len(), 2);
+    assert_eq!(bindings[0].mode, None);
+    assert_eq!(bindings[0].action, Action::Pause);
+    assert_eq!(bindings[1].mode.as_deref(), Some("gaming"));
+    assert_eq!(
+        bindings[1].action,
+        Action::SwitchProfile("burst".to_string())
+    );
+    assert_eq!(bindings[0].trigger, Trigger::Press);
+    assert_eq!(bindings[0].cooldown_ms, 150);
+}
+
+#[test]
+fn test_hotkey_binding_explicit_trigger_and_cooldown() {
+    use process_key_sender::{HotkeyBinding, Trigger};
+
+    let json = r#"{"hotkey": "ctrl+alt+r", "action": "pause", "trigger": "release", "cooldown_ms": 500}"#;
+    let binding: HotkeyBinding = serde_json::from_str(json).unwrap();
+    assert_eq!(binding.trigger, Trigger::Release);
+    assert_eq!(binding.cooldown_ms, 500);
+}
+
+#[test]
+fn test_format_hotkey_round_trips_through_canonical_string() {
+    use global_hotkey::hotkey::{Code, HotKey, Modifiers};
+    use process_key_sender::{format_hotkey, parse_hotkey};
+
+    let hotkey = HotKey::new(Some(Modifiers::CONTROL | Modifiers::SHIFT), Code::F5);
+    let label = format_hotkey(&hotkey).unwrap();
+    assert_eq!(label, "ctrl+shift+f5");
+
+    // Re-parsing the label should produce the identical chord id, i.e. the
+    // round-trip is idempotent.
+    let reparsed = parse_hotkey(&label).unwrap();
+    assert_eq!(hotkey.id(), reparsed.id());
+}
+
+#[test]
+fn test_format_hotkey_unsupported_code_errs() {
+    use global_hotkey::hotkey::{Code, HotKey};
+    use process_key_sender::format_hotkey;
+
+    let hotkey = HotKey::new(None, Code::Fn);
+    assert!(format_hotkey(&hotkey).is_err());
+}
+
+#[test]
+fn test_expanded_key_vocabulary_aliases() {
+    use process_key_sender::parse_hotkey;
+
+    for key in [
+        "num5", "numpad5", "kp_add", "plus", "minus", "equal", "bracketleft", "semicolon",
+        "comma", "period", "slash", "grave", "capslock", "printscreen", "scrolllock", "pause",
+        "contextmenu", "f13", "f24",
+    ] {
+        assert!(
+            parse_hotkey(key).is_ok(),
+            "expected '{}' to be a recognized key",
+            key
+        );
+    }
+}
+
+#[test]
+fn test_hotkey_chain_parses_and_formats() {
+    use process_key_sender::{format_hotkey_chain, parse_hotkey_chain};
+
+    let chain = parse_hotkey_chain("ctrl+k, p").unwrap();
+    assert_eq!(chain.len(), 2);
+    assert_eq!(format_hotkey_chain(&chain).unwrap(), "ctrl+k, p");
+
+    // A chain with no comma is just a single-step binding.
+    let single = parse_hotkey_chain("ctrl+alt+r").unwrap();
+    assert_eq!(single.len(), 1);
+}
+
+#[test]
+fn test_expanded_key_vocabulary_round_trips() {
+    use process_key_sender::{format_hotkey, parse_hotkey};
+
+    for key in ["num5", "numadd", "minus", "capslock", "f13"] {
+        let hotkey = parse_hotkey(key).unwrap();
+        let label = format_hotkey(&hotkey).unwrap();
+        let reparsed = parse_hotkey(&label).unwrap();
+        assert_eq!(hotkey.id(), reparsed.id());
+    }
+}
+
 // Error type tests
 
 #[test]